@@ -66,6 +66,7 @@ mod node;
 pub use node::*;
 
 use leo_errors::{AstError, Result};
+use serde::{Deserialize, Serialize};
 
 /// The abstract syntax tree (AST) for a Leo program.
 ///
@@ -99,24 +100,71 @@ impl Ast {
         self.ast
     }
 
-    /// Serializes the ast into a JSON string.
+    /// Wraps the ast in its versioned envelope, ready to be serialized, without cloning it.
+    fn to_envelope(&self) -> AstJsonRef<'_> {
+        AstJsonRef {
+            ast_version: AST_JSON_VERSION,
+            compiler_version: env!("CARGO_PKG_VERSION").to_string(),
+            program: &self.ast,
+        }
+    }
+
+    /// Serializes the ast into a JSON string, wrapped in an envelope carrying the `ast_version`
+    /// it was written with.
     pub fn to_json_string(&self) -> Result<String> {
-        Ok(serde_json::to_string_pretty(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
+        Ok(serde_json::to_string_pretty(&self.to_envelope())
+            .map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
     }
 
-    /// Serializes the ast into a JSON file.
+    /// Serializes the ast into a JSON file, wrapped in an envelope carrying the `ast_version` it
+    /// was written with.
     pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
         path.push(file_name);
         let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
         let writer = std::io::BufWriter::new(file);
-        Ok(serde_json::to_writer_pretty(writer, &self.ast)
+        Ok(serde_json::to_writer_pretty(writer, &self.to_envelope())
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
-    /// Deserializes the JSON string into a ast.
+    /// Deserializes the JSON string into an ast.
+    ///
+    /// The string is expected to carry an `ast_version` envelope. Dumps from an older known
+    /// version are migrated forward via [`AST_MIGRATIONS`] before being deserialized; dumps
+    /// without an envelope at all (from before versioning was introduced) are treated as version
+    /// `0`. An unknown, newer `ast_version` is rejected with [`AstError::unsupported_ast_version`].
     pub fn from_json_string(json: &str) -> Result<Self> {
-        let ast: Program = serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
-        Ok(Self { ast })
+        let mut value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
+
+        if value.get("ast_version").is_none() {
+            value = wrap_unversioned(value);
+        }
+
+        let mut version = value
+            .get("ast_version")
+            .and_then(serde_json::Value::as_u64)
+            .map(|version| version as u32)
+            .ok_or_else(|| AstError::failed_to_read_json_string_to_ast(&"`ast_version` must be an integer"))?;
+
+        if version > AST_JSON_VERSION {
+            return Err(AstError::unsupported_ast_version(version, AST_JSON_VERSION).into());
+        }
+
+        while version < AST_JSON_VERSION {
+            let migration = AST_MIGRATIONS
+                .get(version as usize)
+                .ok_or_else(|| AstError::unsupported_ast_version(version, AST_JSON_VERSION))?;
+            value = migration(value)?;
+            version += 1;
+            // Keep the envelope's own `ast_version` field in step with the version the loop has
+            // just migrated it to, so a migration that inspects it sees a consistent value.
+            value["ast_version"] = serde_json::Value::from(version);
+        }
+
+        let envelope: AstJson =
+            serde_json::from_value(value).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
+        debug_assert_eq!(envelope.ast_version, version, "migrations should land the envelope at the version they migrated to");
+        Ok(Self { ast: envelope.program })
     }
 
     /// Deserializes the JSON string into a ast from a file.
@@ -124,6 +172,36 @@ impl Ast {
         let data = std::fs::read_to_string(&path).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?;
         Self::from_json_string(&data)
     }
+
+    /// Serializes the ast into a compact binary representation via `bincode`.
+    ///
+    /// Unlike the JSON format, this is not meant for human inspection or cross-version
+    /// compatibility: it is a fast cache format for intermediate ASTs the compiler loads
+    /// repeatedly in the same build, e.g. for imported packages.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(bincode::serialize(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_bytes(&e))?)
+    }
+
+    /// Serializes the ast into a binary file via `bincode`. See [`Ast::to_bytes`].
+    pub fn to_binary_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_binary_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(bincode::serialize_into(writer, &self.ast)
+            .map_err(|e| AstError::failed_to_write_ast_to_binary_file(&path, &e))?)
+    }
+
+    /// Deserializes the binary representation produced by [`Ast::to_bytes`] into an ast.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let ast: Program = bincode::deserialize(bytes).map_err(|e| AstError::failed_to_read_bytes_to_ast(&e))?;
+        Ok(Self { ast })
+    }
+
+    /// Deserializes the binary file produced by [`Ast::to_binary_file`] into an ast.
+    pub fn from_binary_file(path: std::path::PathBuf) -> Result<Self> {
+        let data = std::fs::read(&path).map_err(|e| AstError::failed_to_read_binary_file(&path, &e))?;
+        Self::from_bytes(&data)
+    }
 }
 
 impl AsRef<Program> for Ast {
@@ -131,3 +209,106 @@ impl AsRef<Program> for Ast {
         &self.ast
     }
 }
+
+/// The current on-disk schema version for a serialized [`Ast`].
+///
+/// Bump this whenever a change to the node types would change the shape of the serialized
+/// [`Program`], and append a migration to [`AST_MIGRATIONS`] so that dumps written by older
+/// compiler releases keep loading.
+const AST_JSON_VERSION: u32 = 1;
+
+/// The envelope a [`Program`] is wrapped in when serializing, carrying the schema version and
+/// compiler version it was written with. Borrows the [`Program`] so that serializing an [`Ast`]
+/// doesn't require cloning the whole tree.
+#[derive(Serialize)]
+struct AstJsonRef<'a> {
+    ast_version: u32,
+    compiler_version: String,
+    program: &'a Program,
+}
+
+/// The owned counterpart of [`AstJsonRef`], used when deserializing a stored envelope back into
+/// an [`Ast`].
+#[derive(Deserialize)]
+struct AstJson {
+    ast_version: u32,
+    #[allow(dead_code)]
+    compiler_version: String,
+    program: Program,
+}
+
+/// A migration that rewrites an AST JSON envelope from the `ast_version` equal to its index in
+/// [`AST_MIGRATIONS`] into the next version's shape.
+type AstMigration = fn(serde_json::Value) -> Result<serde_json::Value>;
+
+/// Migrations from a previous [`AST_JSON_VERSION`] to the next, indexed by the version they
+/// migrate *from*. The gap between the pre-versioning format (treated as version `0`) and version
+/// `1` is a no-op: [`wrap_unversioned`] already produces the version `1` shape, so there's no
+/// field rewriting to do (the `from_json_string` loop bumps the envelope's `ast_version` field
+/// itself). When the envelope shape actually changes, push the new migration here rather than
+/// removing the ones before it.
+const AST_MIGRATIONS: &[AstMigration] = &[identity_migration];
+
+/// A migration that performs no shape rewriting, used for version gaps where the envelope's
+/// fields don't need to change.
+fn identity_migration(value: serde_json::Value) -> Result<serde_json::Value> {
+    Ok(value)
+}
+
+/// Wraps a bare, pre-versioning `Program` dump (as emitted before the `ast_version` envelope
+/// existed) into the version `0` envelope shape, so it can flow through [`AST_MIGRATIONS`] like
+/// any other legacy version.
+fn wrap_unversioned(program: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "ast_version": 0,
+        "compiler_version": "unknown",
+        "program": program,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_json_string_round_trips_the_current_version() {
+        let ast = Ast::new(Program::default());
+        let json = ast.to_json_string().expect("serializing the current envelope should succeed");
+        let recovered = Ast::from_json_string(&json).expect("the envelope we just wrote should read back");
+        assert_eq!(ast, recovered);
+    }
+
+    #[test]
+    fn from_json_string_migrates_a_legacy_unversioned_dump() {
+        let ast = Ast::new(Program::default());
+        // Dumps from before the `ast_version` envelope existed were a bare `Program`.
+        let legacy = serde_json::to_string(ast.as_repr()).expect("serializing a bare program should succeed");
+        let recovered = Ast::from_json_string(&legacy).expect("a legacy, unversioned dump should still load");
+        assert_eq!(ast, recovered);
+    }
+
+    #[test]
+    fn from_json_string_rejects_an_unsupported_future_version() {
+        let future = serde_json::json!({
+            "ast_version": AST_JSON_VERSION + 1,
+            "compiler_version": "0.0.0",
+            "program": Program::default(),
+        })
+        .to_string();
+
+        let err = Ast::from_json_string(&future).expect_err("a newer-than-supported version must be rejected");
+        assert!(matches!(
+            err,
+            AstError::UnsupportedAstVersion { found, supported }
+                if found == AST_JSON_VERSION + 1 && supported == AST_JSON_VERSION
+        ));
+    }
+
+    #[test]
+    fn bytes_round_trip_preserves_the_ast() {
+        let ast = Ast::new(Program::default());
+        let bytes = ast.to_bytes().expect("serializing to bincode should succeed");
+        let recovered = Ast::from_bytes(&bytes).expect("the bytes we just wrote should read back");
+        assert_eq!(ast, recovered);
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Errors raised while constructing, canonicalizing, or (de)serializing a Leo `Ast`.
+
+use std::{fmt::Display, path::Path};
+
+use thiserror::Error;
+
+/// Errors produced while working with a Leo `Ast`.
+#[derive(Debug, Error)]
+pub enum AstError {
+    #[error("failed to convert ast to a json string: {0}")]
+    FailedToConvertAstToJsonString(String),
+
+    #[error("failed to create ast json file `{0}`: {1}")]
+    FailedToCreateAstJsonFile(String, String),
+
+    #[error("failed to write ast to json file `{0}`: {1}")]
+    FailedToWriteAstToJsonFile(String, String),
+
+    #[error("failed to read json string to ast: {0}")]
+    FailedToReadJsonStringToAst(String),
+
+    #[error("failed to read ast json file `{0}`: {1}")]
+    FailedToReadJsonFile(String, String),
+
+    /// The `ast_version` on a serialized AST is one this compiler can't read: newer than the
+    /// current version, or an older version with no registered migration.
+    #[error("unsupported ast version `{found}`; this compiler supports up to version `{supported}`")]
+    UnsupportedAstVersion { found: u32, supported: u32 },
+
+    #[error("failed to convert ast to bytes: {0}")]
+    FailedToConvertAstToBytes(String),
+
+    #[error("failed to create ast binary file `{0}`: {1}")]
+    FailedToCreateAstBinaryFile(String, String),
+
+    #[error("failed to write ast to binary file `{0}`: {1}")]
+    FailedToWriteAstToBinaryFile(String, String),
+
+    #[error("failed to read bytes to ast: {0}")]
+    FailedToReadBytesToAst(String),
+
+    #[error("failed to read ast binary file `{0}`: {1}")]
+    FailedToReadBinaryFile(String, String),
+}
+
+impl AstError {
+    pub fn failed_to_convert_ast_to_json_string(error: &impl Display) -> Self {
+        Self::FailedToConvertAstToJsonString(error.to_string())
+    }
+
+    pub fn failed_to_create_ast_json_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToCreateAstJsonFile(path.display().to_string(), error.to_string())
+    }
+
+    pub fn failed_to_write_ast_to_json_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToWriteAstToJsonFile(path.display().to_string(), error.to_string())
+    }
+
+    pub fn failed_to_read_json_string_to_ast(error: &impl Display) -> Self {
+        Self::FailedToReadJsonStringToAst(error.to_string())
+    }
+
+    pub fn failed_to_read_json_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToReadJsonFile(path.display().to_string(), error.to_string())
+    }
+
+    /// The `ast_version` found in a serialized AST (`found`) can't be read by this compiler, whose
+    /// current version is `supported`: either `found` is newer, i.e. the dump was produced by a
+    /// newer compiler, or it is an older version with no registered migration.
+    pub fn unsupported_ast_version(found: u32, supported: u32) -> Self {
+        Self::UnsupportedAstVersion { found, supported }
+    }
+
+    pub fn failed_to_convert_ast_to_bytes(error: &impl Display) -> Self {
+        Self::FailedToConvertAstToBytes(error.to_string())
+    }
+
+    pub fn failed_to_create_ast_binary_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToCreateAstBinaryFile(path.display().to_string(), error.to_string())
+    }
+
+    pub fn failed_to_write_ast_to_binary_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToWriteAstToBinaryFile(path.display().to_string(), error.to_string())
+    }
+
+    pub fn failed_to_read_bytes_to_ast(error: &impl Display) -> Self {
+        Self::FailedToReadBytesToAst(error.to_string())
+    }
+
+    pub fn failed_to_read_binary_file(path: &Path, error: &impl Display) -> Self {
+        Self::FailedToReadBinaryFile(path.display().to_string(), error.to_string())
+    }
+}